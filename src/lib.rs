@@ -23,7 +23,39 @@ pub use paste::item as paste_item;
 /// - `into_<variant>` - moves the variant out of the union, consuming the union. Unsound if the
 ///   union is not of that variant.
 ///
-/// **Note: fields must be `Copy`.**
+/// Except in the `drop` form (see below), the generated type is also `Copy` and `Clone`, since
+/// every member is then required to be `Copy`.
+///
+/// **Note: fields must be `Copy`.** This is enforced with a compile-time assertion, so misuse is
+/// a hard error in both debug and release builds rather than latent undefined behaviour.
+///
+/// # `const` unions
+///
+/// Prefixing a definition with `const` (i.e. `const union Foo { ... }`) additionally makes
+/// `new_<variant>`, `get_<variant>` and `into_<variant>` callable from `const` contexts, which is
+/// useful for building static lookup tables. This is opt-in, since not every member type can be
+/// used in a `const fn` on every toolchain.
+///
+/// # `tagged` unions
+///
+/// Prefixing a definition with `tagged` (i.e. `tagged union Foo { ... }`) keeps a discriminant
+/// alongside the data in release builds too, rather than relying on the caller to remember which
+/// variant is active. This is opt-in, since it costs the size of the discriminant and gives up
+/// some layout control compared to a bare union. In addition to the usual unsafe accessors, a
+/// tagged union gets safe `is_<variant>(&self) -> bool`, `try_get_<variant>(&self) -> Option<&T>`
+/// and `try_into_<variant>(self) -> Option<T>` methods that check the discriminant at runtime in
+/// both profiles, mirroring the ergonomics of `matches!` for a real enum.
+///
+/// # `drop` unions
+///
+/// Prefixing a definition with `drop` (i.e. `drop union Foo { ... }`) lifts the `Copy`
+/// restriction, at the cost of a runtime discriminant in release mode: each release-mode field is
+/// wrapped in `core::mem::ManuallyDrop` and `$name` gets a `Drop` impl that consults the
+/// discriminant to run the right destructor. `set_<variant>` drops the previously-active value
+/// before overwriting it. Debug mode needs no extra bookkeeping, since the inner `enum` already
+/// drops correctly on its own. This form is opt-in so the existing zero-overhead `Copy`-only path
+/// stays the default; `into_<variant>` uses `ManuallyDrop::take` internally and forgets `self`
+/// afterwards to avoid dropping the moved-out value a second time.
 ///
 /// # Example
 ///
@@ -37,6 +69,21 @@ pub use paste::item as paste_item;
 ///         private: f32,
 ///     }
 ///
+///     pub const union ConstExample {
+///         pub one: u32,
+///         pub two: f32,
+///     }
+///
+///     pub tagged union TaggedExample {
+///         pub one: u32,
+///         pub two: f32,
+///     }
+///
+///     pub drop union DropExample {
+///         pub one: String,
+///         pub two: u32,
+///     }
+///
 ///     pub union GenericExample<T: Copy, U>
 ///        where U: Copy + Clone
 ///     {
@@ -47,124 +94,881 @@ pub use paste::item as paste_item;
 /// ```
 #[macro_export]
 macro_rules! union {
-    {
+    () => {};
+
+    (
+        $(#[$union_meta:meta])*
+        $union_vis:vis const union $name:ident$(<$($generic:ident $(: $generic_trait:ty)?$(,)?)*>)?
+        $(
+            where $($where_generic:ident: $($where_bound:ty)+)*
+        )?
+        {
+            $($member_vis:vis $member:ident: $member_type:ty,)*
+        }
+        $($rest:tt)*
+    ) => {
+        $crate::__union_shared! {
+            $(#[$union_meta])*
+            $union_vis union $name$(<$($generic$(: $generic_trait)?,)*>)?
+            $(where $($where_generic: $($where_bound)*)*)?
+            {
+                $($member_vis $member: $member_type,)*
+            }
+        }
+
+        $crate::__union_ctors! {
+            const
+            $name$(<$($generic$(: $generic_trait)?,)*>)?
+            $(where $($where_generic: $($where_bound)*)*)?
+            {
+                $($member_vis $member: $member_type,)*
+            }
+        }
+
+        $crate::union! { $($rest)* }
+    };
+
+    (
+        $(#[$union_meta:meta])*
+        $union_vis:vis tagged union $name:ident$(<$($generic:ident $(: $generic_trait:ty)?$(,)?)*>)?
+        $(
+            where $($where_generic:ident: $($where_bound:ty)+)*
+        )?
+        {
+            $($member_vis:vis $member:ident: $member_type:ty,)*
+        }
+        $($rest:tt)*
+    ) => {
+        $crate::__union_tagged! {
+            $(#[$union_meta])*
+            $union_vis union $name$(<$($generic$(: $generic_trait)?,)*>)?
+            $(where $($where_generic: $($where_bound)*)*)?
+            {
+                $($member_vis $member: $member_type,)*
+            }
+        }
+
+        $crate::union! { $($rest)* }
+    };
+
+    (
+        $(#[$union_meta:meta])*
+        $union_vis:vis drop union $name:ident$(<$($generic:ident $(: $generic_trait:ty)?$(,)?)*>)?
+        $(
+            where $($where_generic:ident: $($where_bound:ty)+)*
+        )?
+        {
+            $($member_vis:vis $member:ident: $member_type:ty,)*
+        }
+        $($rest:tt)*
+    ) => {
+        $crate::__union_drop! {
+            $(#[$union_meta])*
+            $union_vis union $name$(<$($generic$(: $generic_trait)?,)*>)?
+            $(where $($where_generic: $($where_bound)*)*)?
+            {
+                $($member_vis $member: $member_type,)*
+            }
+        }
+
+        $crate::union! { $($rest)* }
+    };
+
+    (
+        $(#[$union_meta:meta])*
+        $union_vis:vis union $name:ident$(<$($generic:ident $(: $generic_trait:ty)?$(,)?)*>)?
+        $(
+            where $($where_generic:ident: $($where_bound:ty)+)*
+        )?
+        {
+            $($member_vis:vis $member:ident: $member_type:ty,)*
+        }
+        $($rest:tt)*
+    ) => {
+        $crate::__union_shared! {
+            $(#[$union_meta])*
+            $union_vis union $name$(<$($generic$(: $generic_trait)?,)*>)?
+            $(where $($where_generic: $($where_bound)*)*)?
+            {
+                $($member_vis $member: $member_type,)*
+            }
+        }
+
+        $crate::__union_ctors! {
+            $name$(<$($generic$(: $generic_trait)?,)*>)?
+            $(where $($where_generic: $($where_bound)*)*)?
+            {
+                $($member_vis $member: $member_type,)*
+            }
+        }
+
+        $crate::union! { $($rest)* }
+    };
+}
+
+/// Generates a compile-time assertion that every member of a union is `Copy`. Shared by every
+/// form that requires `Copy` members (the plain and `const` forms via `__union_shared`, and
+/// `__union_tagged`), since in release mode their `Inner` representation is a `union`, and
+/// reading a non-`Copy` field out of a union is unsound. This mirrors the `AssertParamIsCopy`
+/// trick `#[derive(Copy)]` uses internally, so the bound is checked at compile time in both
+/// profiles instead of only being documented.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __union_assert_copy {
+    (
+        $name:ident$(<$($generic:ident $(: $generic_trait:ty)?$(,)?)*>)?
         $(
-            $(#[$union_meta:meta])*
-            $union_vis:vis union $name:ident$(<$($generic:ident $(: $generic_trait:ty)?$(,)?)*>)?
+            where $($where_generic:ident: $($where_bound:ty)+)*
+        )?
+        {
+            $($member_vis:vis $member:ident: $member_type:ty,)*
+        }
+    ) => {
+        $crate::paste_item! {
+            #[allow(non_camel_case_types, dead_code)]
+            struct [<_AssertCopy $name>]<__T: ::core::marker::Copy + ?Sized>(
+                ::core::marker::PhantomData<__T>,
+            );
+
+            #[allow(non_snake_case, dead_code)]
+            fn [<_assert_copy_ $name>]$(<$($generic$(: $generic_trait)?,)*>)?()
             $(
-                where $($where_generic:ident: $($where_bound:ty)+)*
+                where $($where_generic: $($where_bound)*)*
             )?
             {
-                $($member_vis:vis $member:ident: $member_type:ty,)*
+                $(
+                    let _: [<_AssertCopy $name>]<$member_type>;
+                )*
             }
-        )*
-    } => {
+        }
+    };
+}
+
+/// Generates the pieces of a union that don't depend on whether it was declared `const`: the
+/// `Inner` representation (`enum` in debug, `union` in release), the compile-time `Copy`
+/// assertion, `get_<variant>_mut`/`set_<variant>`, and the `Copy`/`Clone` impls for the wrapper.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __union_shared {
+    (
+        $(#[$union_meta:meta])*
+        $union_vis:vis union $name:ident$(<$($generic:ident $(: $generic_trait:ty)?$(,)?)*>)?
         $(
-            #[cfg(debug_assertions)]
-            $crate::paste_item! {
-                #[allow(non_camel_case_types)]
-                enum [<$name Inner>]$(<$($generic,)*>)?
+            where $($where_generic:ident: $($where_bound:ty)+)*
+        )?
+        {
+            $($member_vis:vis $member:ident: $member_type:ty,)*
+        }
+    ) => {
+        $crate::__union_assert_copy! {
+            $name$(<$($generic$(: $generic_trait)?,)*>)?
+            $(where $($where_generic: $($where_bound)*)*)?
+            {
+                $($member_vis $member: $member_type,)*
+            }
+        }
+
+        #[cfg(debug_assertions)]
+        $crate::paste_item! {
+            #[allow(non_camel_case_types)]
+            enum [<$name Inner>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
                 $(
-                    where $($where_generic: $($where_bound)*)*
-                )?
-                {
-                    $(
-                        $member($member_type),
-                    )*
-                }
+                    $member($member_type),
+                )*
+            }
 
-                #[allow(dead_code)]
-                impl$(<$($generic$(: $generic_trait)?,)*>)? $name$(<$($generic,)*>)?
+            #[allow(dead_code)]
+            impl$(<$($generic$(: $generic_trait)?,)*>)? $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
                 $(
-                    where $($where_generic: $($where_bound)*)*
-                )?
-                {
-                    $(
-                        $member_vis fn [<new_ $member>](val: $member_type) -> Self {
-                            Self([<$name Inner>]::$member(val))
+                    $member_vis unsafe fn [<get_ $member _mut>](&mut self) -> &mut $member_type {
+                        match &mut self.0 {
+                            [<$name Inner>]::$member(val) => val,
+                            _ => panic!("unexpected union member")
                         }
+                    }
 
-                        $member_vis unsafe fn [<get_ $member>](&self) -> &$member_type {
-                            match &self.0 {
-                                [<$name Inner>]::$member(val) => val,
-                                _ => panic!("unexpected union member")
-                            }
+                    $member_vis unsafe fn [<set_ $member>](&mut self, new: $member_type) {
+                        self.0 = [<$name Inner>]::$member(new);
+                    }
+                )*
+            }
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::marker::Copy for [<$name Inner>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {}
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::clone::Clone for [<$name Inner>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                #[inline]
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+        }
+
+        #[cfg(not(debug_assertions))]
+        $crate::paste_item! {
+            union [<$name Inner>]$(<$($generic$(: $generic_trait)?,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                $($member: $member_type,)*
+            }
+
+            #[allow(dead_code)]
+            impl$(<$($generic$(: $generic_trait)?,)*>)? $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                $(
+                    $member_vis unsafe fn [<get_ $member _mut>](&mut self) -> &mut $member_type {
+                        &mut (self.0).$member
+                    }
+
+                    $member_vis unsafe fn [<set_ $member>](&mut self, new: $member_type) {
+                        (self.0).$member = new;
+                    }
+                )*
+            }
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::marker::Copy for [<$name Inner>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {}
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::clone::Clone for [<$name Inner>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                #[inline]
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+        }
+
+        $crate::paste_item! {
+            #[repr(transparent)]
+            $(#[$union_meta])*
+            $union_vis struct $name$(<$($generic$(: $generic_trait)?,)*>)?(
+                    [<$name Inner>]$(<$($generic,)*>)?
+            )
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?;
+
+            // Every member is required to be `Copy`, so the wrapper can be `Copy` too.
+            // Written by hand rather than derived, since `#[derive(Copy, Clone)]` can't be
+            // placed on the union in release mode.
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::marker::Copy for $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {}
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::clone::Clone for $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                #[inline]
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+        }
+    };
+}
+
+/// Generates `new_<variant>`, `get_<variant>` and `into_<variant>`, optionally as `const fn`
+/// when invoked with a leading `const`. Kept separate from `__union_shared` because these are
+/// the only methods whose signature depends on constness.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __union_ctors {
+    (
+        const
+        $name:ident$(<$($generic:ident $(: $generic_trait:ty)?$(,)?)*>)?
+        $(
+            where $($where_generic:ident: $($where_bound:ty)+)*
+        )?
+        {
+            $($member_vis:vis $member:ident: $member_type:ty,)*
+        }
+    ) => {
+        #[cfg(debug_assertions)]
+        $crate::paste_item! {
+            #[allow(dead_code)]
+            impl$(<$($generic$(: $generic_trait)?,)*>)? $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                $(
+                    $member_vis const fn [<new_ $member>](val: $member_type) -> Self {
+                        Self([<$name Inner>]::$member(val))
+                    }
+
+                    $member_vis const unsafe fn [<get_ $member>](&self) -> &$member_type {
+                        match &self.0 {
+                            [<$name Inner>]::$member(val) => val,
+                            _ => panic!("unexpected union member")
                         }
+                    }
 
-                        $member_vis unsafe fn [<get_ $member _mut>](&mut self) -> &mut $member_type {
-                            match &mut self.0 {
-                                [<$name Inner>]::$member(val) => val,
-                                _ => panic!("unexpected union member")
-                            }
+                    $member_vis const unsafe fn [<into_ $member>](self) -> $member_type {
+                        match self.0 {
+                            [<$name Inner>]::$member(val) => val,
+                            _ => panic!("unexpected union member")
                         }
+                    }
+                )*
+            }
+        }
+
+        #[cfg(not(debug_assertions))]
+        $crate::paste_item! {
+            #[allow(dead_code)]
+            impl$(<$($generic$(: $generic_trait)?,)*>)? $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                $(
+                    $member_vis const fn [<new_ $member>](val: $member_type) -> Self {
+                        Self([<$name Inner>] {
+                            $member: val,
+                        })
+                    }
+
+                    $member_vis const unsafe fn [<get_ $member>](&self) -> &$member_type {
+                        &(self.0).$member
+                    }
+
+                    $member_vis const unsafe fn [<into_ $member>](self) -> $member_type {
+                        (self.0).$member
+                    }
+                )*
+            }
+        }
+    };
+
+    (
+        $name:ident$(<$($generic:ident $(: $generic_trait:ty)?$(,)?)*>)?
+        $(
+            where $($where_generic:ident: $($where_bound:ty)+)*
+        )?
+        {
+            $($member_vis:vis $member:ident: $member_type:ty,)*
+        }
+    ) => {
+        #[cfg(debug_assertions)]
+        $crate::paste_item! {
+            #[allow(dead_code)]
+            impl$(<$($generic$(: $generic_trait)?,)*>)? $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                $(
+                    $member_vis fn [<new_ $member>](val: $member_type) -> Self {
+                        Self([<$name Inner>]::$member(val))
+                    }
 
-                        $member_vis unsafe fn [<set_ $member>](&mut self, new: $member_type) {
-                            self.0 = [<$name Inner>]::$member(new);
+                    $member_vis unsafe fn [<get_ $member>](&self) -> &$member_type {
+                        match &self.0 {
+                            [<$name Inner>]::$member(val) => val,
+                            _ => panic!("unexpected union member")
                         }
+                    }
 
-                        $member_vis unsafe fn [<into_ $member>](self) -> $member_type {
-                            match self.0 {
-                                [<$name Inner>]::$member(val) => val,
-                                _ => panic!("unexpected union member")
-                            }
+                    $member_vis unsafe fn [<into_ $member>](self) -> $member_type {
+                        match self.0 {
+                            [<$name Inner>]::$member(val) => val,
+                            _ => panic!("unexpected union member")
                         }
-                    )*
-                }
+                    }
+                )*
+            }
+        }
+
+        #[cfg(not(debug_assertions))]
+        $crate::paste_item! {
+            #[allow(dead_code)]
+            impl$(<$($generic$(: $generic_trait)?,)*>)? $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                $(
+                    $member_vis fn [<new_ $member>](val: $member_type) -> Self {
+                        Self([<$name Inner>] {
+                            $member: val,
+                        })
+                    }
+
+                    $member_vis unsafe fn [<get_ $member>](&self) -> &$member_type {
+                        &(self.0).$member
+                    }
+
+                    $member_vis unsafe fn [<into_ $member>](self) -> $member_type {
+                        (self.0).$member
+                    }
+                )*
+            }
+        }
+    };
+}
+
+/// Generates a tagged union (the `tagged union Foo { ... }` form): a discriminant is kept
+/// alongside the data in release mode too, rather than only in debug mode, so that safe
+/// `is_<variant>`/`try_get_<variant>`/`try_into_<variant>` accessors can check it at runtime in
+/// both profiles. This is a self-contained mode, so unlike the plain/`const` forms it isn't
+/// split into separate "shared" and "ctors" halves.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __union_tagged {
+    (
+        $(#[$union_meta:meta])*
+        $union_vis:vis union $name:ident$(<$($generic:ident $(: $generic_trait:ty)?$(,)?)*>)?
+        $(
+            where $($where_generic:ident: $($where_bound:ty)+)*
+        )?
+        {
+            $($member_vis:vis $member:ident: $member_type:ty,)*
+        }
+    ) => {
+        $crate::__union_assert_copy! {
+            $name$(<$($generic$(: $generic_trait)?,)*>)?
+            $(where $($where_generic: $($where_bound)*)*)?
+            {
+                $($member_vis $member: $member_type,)*
             }
+        }
 
-            #[cfg(not(debug_assertions))]
-            $crate::paste_item! {
-                union [<$name Inner>]$(<$($generic$(: $generic_trait)?,)*>)?
+        #[cfg(debug_assertions)]
+        $crate::paste_item! {
+            #[allow(non_camel_case_types)]
+            enum [<$name Inner>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
                 $(
-                    where $($where_generic: $($where_bound)*)*
-                )?
-                {
-                    $($member: $member_type,)*
+                    $member($member_type),
+                )*
+            }
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::marker::Copy for [<$name Inner>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {}
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::clone::Clone for [<$name Inner>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                #[inline]
+                fn clone(&self) -> Self {
+                    *self
                 }
+            }
 
-                #[allow(dead_code)]
-                impl$(<$($generic$(: $generic_trait)?,)*>)? $name$(<$($generic,)*>)?
+            #[allow(dead_code)]
+            impl$(<$($generic$(: $generic_trait)?,)*>)? $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
                 $(
-                    where $($where_generic: $($where_bound)*)*
-                )?
-                {
-                    $(
-                        $member_vis fn [<new_ $member>](val: $member_type) -> Self {
-                            Self([<$name Inner>] {
-                                $member: val,
-                            })
+                    $member_vis fn [<new_ $member>](val: $member_type) -> Self {
+                        Self([<$name Inner>]::$member(val))
+                    }
+
+                    $member_vis fn [<is_ $member>](&self) -> bool {
+                        ::core::matches!(&self.0, [<$name Inner>]::$member(_))
+                    }
+
+                    $member_vis unsafe fn [<get_ $member>](&self) -> &$member_type {
+                        match &self.0 {
+                            [<$name Inner>]::$member(val) => val,
+                            _ => panic!("unexpected union member")
                         }
+                    }
 
-                        $member_vis unsafe fn [<get_ $member>](&self) -> &$member_type {
-                            &(self.0).$member
+                    $member_vis fn [<try_get_ $member>](&self) -> Option<&$member_type> {
+                        match &self.0 {
+                            [<$name Inner>]::$member(val) => Some(val),
+                            _ => None,
                         }
+                    }
 
-                        $member_vis unsafe fn [<get_ $member _mut>](&mut self) -> &mut $member_type {
-                            &mut (self.0).$member
+                    $member_vis unsafe fn [<get_ $member _mut>](&mut self) -> &mut $member_type {
+                        match &mut self.0 {
+                            [<$name Inner>]::$member(val) => val,
+                            _ => panic!("unexpected union member")
                         }
+                    }
+
+                    $member_vis unsafe fn [<set_ $member>](&mut self, new: $member_type) {
+                        self.0 = [<$name Inner>]::$member(new);
+                    }
 
-                        $member_vis unsafe fn [<set_ $member>](&mut self, new: $member_type) {
-                            (self.0).$member = new;
+                    $member_vis unsafe fn [<into_ $member>](self) -> $member_type {
+                        match self.0 {
+                            [<$name Inner>]::$member(val) => val,
+                            _ => panic!("unexpected union member")
                         }
+                    }
 
-                        $member_vis unsafe fn [<into_ $member>](self) -> $member_type {
-                            (self.0).$member
+                    $member_vis fn [<try_into_ $member>](self) -> Option<$member_type> {
+                        match self.0 {
+                            [<$name Inner>]::$member(val) => Some(val),
+                            _ => None,
                         }
-                    )*
+                    }
+                )*
+            }
+        }
+
+        #[cfg(not(debug_assertions))]
+        $crate::paste_item! {
+            #[allow(non_camel_case_types)]
+            #[derive(::core::marker::Copy, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+            #[repr(u8)]
+            enum [<$name Tag>] {
+                $($member,)*
+            }
+
+            #[allow(non_camel_case_types)]
+            union [<$name Data>]$(<$($generic$(: $generic_trait)?,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                $($member: $member_type,)*
+            }
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::marker::Copy for [<$name Data>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {}
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::clone::Clone for [<$name Data>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                #[inline]
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            #[allow(non_camel_case_types)]
+            struct [<$name Inner>]$(<$($generic$(: $generic_trait)?,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                tag: [<$name Tag>],
+                data: [<$name Data>]$(<$($generic,)*>)?,
+            }
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::marker::Copy for [<$name Inner>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {}
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::clone::Clone for [<$name Inner>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                #[inline]
+                fn clone(&self) -> Self {
+                    *self
                 }
             }
 
-            $crate::paste_item! {
-                #[repr(transparent)]
-                $(#[$union_meta])*
-                $union_vis struct $name$(<$($generic$(: $generic_trait)?,)*>)?(
-                        [<$name Inner>]$(<$($generic,)*>)?
-                )
+            #[allow(dead_code)]
+            impl$(<$($generic$(: $generic_trait)?,)*>)? $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
                 $(
-                    where $($where_generic: $($where_bound)*)*
-                )?;
+                    $member_vis fn [<new_ $member>](val: $member_type) -> Self {
+                        Self([<$name Inner>] {
+                            tag: [<$name Tag>]::$member,
+                            data: [<$name Data>] { $member: val },
+                        })
+                    }
+
+                    $member_vis fn [<is_ $member>](&self) -> bool {
+                        self.0.tag == [<$name Tag>]::$member
+                    }
+
+                    $member_vis unsafe fn [<get_ $member>](&self) -> &$member_type {
+                        &self.0.data.$member
+                    }
+
+                    $member_vis fn [<try_get_ $member>](&self) -> Option<&$member_type> {
+                        if self.[<is_ $member>]() {
+                            Some(unsafe { self.[<get_ $member>]() })
+                        } else {
+                            None
+                        }
+                    }
+
+                    $member_vis unsafe fn [<get_ $member _mut>](&mut self) -> &mut $member_type {
+                        &mut self.0.data.$member
+                    }
+
+                    $member_vis unsafe fn [<set_ $member>](&mut self, new: $member_type) {
+                        self.0 = [<$name Inner>] {
+                            tag: [<$name Tag>]::$member,
+                            data: [<$name Data>] { $member: new },
+                        };
+                    }
+
+                    $member_vis unsafe fn [<into_ $member>](self) -> $member_type {
+                        self.0.data.$member
+                    }
+
+                    $member_vis fn [<try_into_ $member>](self) -> Option<$member_type> {
+                        if self.[<is_ $member>]() {
+                            Some(unsafe { self.[<into_ $member>]() })
+                        } else {
+                            None
+                        }
+                    }
+                )*
             }
-        )*
+        }
+
+        $crate::paste_item! {
+            #[repr(transparent)]
+            $(#[$union_meta])*
+            $union_vis struct $name$(<$($generic$(: $generic_trait)?,)*>)?(
+                    [<$name Inner>]$(<$($generic,)*>)?
+            )
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?;
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::marker::Copy for $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {}
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::clone::Clone for $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                #[inline]
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+        }
+    };
+}
+
+/// Generates a union that supports non-`Copy` fields (the `drop union Foo { ... }` form).
+/// Debug mode is a plain `enum`, which already drops correctly on its own. Release mode wraps
+/// each field in `ManuallyDrop`, tracks the active variant with a discriminant alongside the
+/// data (much like `__union_tagged`), and gives `$name` a `Drop` impl that runs the right
+/// destructor; `set_<variant>` drops the old value first, and `into_<variant>` takes the value
+/// out with `ManuallyDrop::take` then forgets `self` so it isn't dropped a second time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __union_drop {
+    (
+        $(#[$union_meta:meta])*
+        $union_vis:vis union $name:ident$(<$($generic:ident $(: $generic_trait:ty)?$(,)?)*>)?
+        $(
+            where $($where_generic:ident: $($where_bound:ty)+)*
+        )?
+        {
+            $($member_vis:vis $member:ident: $member_type:ty,)*
+        }
+    ) => {
+        #[cfg(debug_assertions)]
+        $crate::paste_item! {
+            #[allow(non_camel_case_types)]
+            enum [<$name Inner>]$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                $(
+                    $member($member_type),
+                )*
+            }
+
+            #[allow(dead_code)]
+            impl$(<$($generic$(: $generic_trait)?,)*>)? $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                $(
+                    $member_vis fn [<new_ $member>](val: $member_type) -> Self {
+                        Self([<$name Inner>]::$member(val))
+                    }
+
+                    $member_vis unsafe fn [<get_ $member>](&self) -> &$member_type {
+                        match &self.0 {
+                            [<$name Inner>]::$member(val) => val,
+                            _ => panic!("unexpected union member")
+                        }
+                    }
+
+                    $member_vis unsafe fn [<get_ $member _mut>](&mut self) -> &mut $member_type {
+                        match &mut self.0 {
+                            [<$name Inner>]::$member(val) => val,
+                            _ => panic!("unexpected union member")
+                        }
+                    }
+
+                    $member_vis unsafe fn [<set_ $member>](&mut self, new: $member_type) {
+                        self.0 = [<$name Inner>]::$member(new);
+                    }
+
+                    $member_vis unsafe fn [<into_ $member>](self) -> $member_type {
+                        match self.0 {
+                            [<$name Inner>]::$member(val) => val,
+                            _ => panic!("unexpected union member")
+                        }
+                    }
+                )*
+            }
+        }
+
+        #[cfg(not(debug_assertions))]
+        $crate::paste_item! {
+            #[allow(non_camel_case_types)]
+            #[derive(::core::marker::Copy, ::core::clone::Clone)]
+            #[repr(u8)]
+            enum [<$name Tag>] {
+                $($member,)*
+            }
+
+            #[allow(non_camel_case_types)]
+            union [<$name Data>]$(<$($generic$(: $generic_trait)?,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                $($member: ::core::mem::ManuallyDrop<$member_type>,)*
+            }
+
+            #[allow(non_camel_case_types)]
+            struct [<$name Inner>]$(<$($generic$(: $generic_trait)?,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                tag: [<$name Tag>],
+                data: [<$name Data>]$(<$($generic,)*>)?,
+            }
+
+            #[allow(dead_code)]
+            impl$(<$($generic$(: $generic_trait)?,)*>)? $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                $(
+                    $member_vis fn [<new_ $member>](val: $member_type) -> Self {
+                        Self([<$name Inner>] {
+                            tag: [<$name Tag>]::$member,
+                            data: [<$name Data>] { $member: ::core::mem::ManuallyDrop::new(val) },
+                        })
+                    }
+
+                    $member_vis unsafe fn [<get_ $member>](&self) -> &$member_type {
+                        &*self.0.data.$member
+                    }
+
+                    $member_vis unsafe fn [<get_ $member _mut>](&mut self) -> &mut $member_type {
+                        &mut *self.0.data.$member
+                    }
+
+                    $member_vis unsafe fn [<set_ $member>](&mut self, new: $member_type) {
+                        self.__drop_active();
+                        self.0 = [<$name Inner>] {
+                            tag: [<$name Tag>]::$member,
+                            data: [<$name Data>] { $member: ::core::mem::ManuallyDrop::new(new) },
+                        };
+                    }
+
+                    // `ManuallyDrop::take` leaves the union's storage bit-for-bit intact, so
+                    // without the `mem::forget` below the `Drop` impl would run the destructor a
+                    // second time on the value we just moved out.
+                    $member_vis unsafe fn [<into_ $member>](mut self) -> $member_type {
+                        let val = ::core::mem::ManuallyDrop::take(&mut self.0.data.$member);
+                        ::core::mem::forget(self);
+                        val
+                    }
+                )*
+
+                unsafe fn __drop_active(&mut self) {
+                    match self.0.tag {
+                        $(
+                            [<$name Tag>]::$member => {
+                                ::core::mem::ManuallyDrop::drop(&mut self.0.data.$member);
+                            }
+                        )*
+                    }
+                }
+            }
+
+            impl$(<$($generic$(: $generic_trait)?,)*>)? ::core::ops::Drop for $name$(<$($generic,)*>)?
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?
+            {
+                fn drop(&mut self) {
+                    unsafe { self.__drop_active(); }
+                }
+            }
+        }
+
+        $crate::paste_item! {
+            #[repr(transparent)]
+            $(#[$union_meta])*
+            $union_vis struct $name$(<$($generic$(: $generic_trait)?,)*>)?(
+                    [<$name Inner>]$(<$($generic,)*>)?
+            )
+            $(
+                where $($where_generic: $($where_bound)*)*
+            )?;
+        }
     };
 }
 
@@ -185,12 +989,49 @@ pub mod example {
             pub one: T,
             pub two: U,
         }
+
+        /// An example union whose accessors can be used in `const` contexts.
+        pub const union ConstExample {
+            pub one: u32,
+            pub two: f32,
+        }
+
+        /// An example union with safe, tag-checked accessors.
+        pub tagged union TaggedExample {
+            pub one: u32,
+            pub two: f32,
+        }
+
+        /// An example union with a non-`Copy` member.
+        pub drop union DropExample {
+            pub one: String,
+            pub two: u32,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::example::{GenericExample, Example};
+    use super::example::{ConstExample, DropExample, Example, GenericExample, TaggedExample};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Increments a shared counter when dropped, so tests can assert a value was dropped
+    /// exactly once (catching leaks and double-drops in the `drop union` bookkeeping).
+    struct DropCounter(Rc<Cell<u32>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    crate::union! {
+        drop union Counted {
+            one: DropCounter,
+            two: DropCounter,
+        }
+    }
 
     #[test]
     fn accessors_simple() {
@@ -242,6 +1083,98 @@ mod tests {
         }
     }
 
+    #[test]
+    #[allow(clippy::clone_on_copy)]
+    fn copy_clone() {
+        let eg_1 = Example::new_two(42);
+        let eg_2 = eg_1;
+        let eg_3 = eg_1.clone();
+
+        unsafe {
+            assert_eq!(*eg_1.get_two(), 42);
+            assert_eq!(*eg_2.get_two(), 42);
+            assert_eq!(*eg_3.get_two(), 42);
+        }
+    }
+
+    #[test]
+    fn const_accessors() {
+        const EG: ConstExample = ConstExample::new_one(7);
+        const VAL: u32 = unsafe { *EG.get_one() };
+        assert_eq!(VAL, 7);
+
+        unsafe {
+            assert_eq!(ConstExample::new_two(4.0).into_two(), 4.0);
+        }
+    }
+
+    #[test]
+    fn tagged_accessors() {
+        let eg_1 = TaggedExample::new_one(42);
+        assert!(eg_1.is_one());
+        assert!(!eg_1.is_two());
+        assert_eq!(eg_1.try_get_one(), Some(&42));
+        assert_eq!(eg_1.try_get_two(), None);
+        assert_eq!(eg_1.try_into_two(), None);
+        assert_eq!(eg_1.try_into_one(), Some(42));
+
+        let eg_2 = TaggedExample::new_two(1.5);
+        assert!(eg_2.is_two());
+        assert_eq!(eg_2.try_get_two(), Some(&1.5));
+        assert_eq!(eg_2.try_get_one(), None);
+    }
+
+    #[test]
+    fn drop_non_copy_field() {
+        let mut eg = DropExample::new_one(String::from("asdfs"));
+
+        unsafe {
+            assert_eq!(*eg.get_one(), "asdfs");
+
+            // Overwriting the active variant must drop the old `String` rather than leak it.
+            eg.set_two(10);
+            assert_eq!(*eg.get_two(), 10);
+
+            assert_eq!(eg.into_two(), 10);
+        }
+
+        // The `String` variant is dropped normally when the union itself goes out of scope.
+        let eg = DropExample::new_one(String::from("owned"));
+        drop(eg);
+
+        unsafe {
+            assert_eq!(DropExample::new_one(String::from("taken")).into_one(), "taken");
+        }
+    }
+
+    #[test]
+    fn drop_counts() {
+        let count_a = Rc::new(Cell::new(0));
+        let count_b = Rc::new(Cell::new(0));
+
+        // `set_<variant>` must drop the previously-active value exactly once, not leak it.
+        let mut u = Counted::new_one(DropCounter(count_a.clone()));
+        unsafe {
+            u.set_two(DropCounter(count_b.clone()));
+        }
+        assert_eq!(count_a.get(), 1);
+        assert_eq!(count_b.get(), 0);
+
+        // Going out of scope must drop the active value exactly once.
+        drop(u);
+        assert_eq!(count_a.get(), 1);
+        assert_eq!(count_b.get(), 1);
+
+        // `into_<variant>` moves the value out via `ManuallyDrop::take`; the union itself must
+        // not drop it a second time once the taken value's own `Drop` runs.
+        let count_c = Rc::new(Cell::new(0));
+        let u = Counted::new_one(DropCounter(count_c.clone()));
+        let taken = unsafe { u.into_one() };
+        assert_eq!(count_c.get(), 0);
+        drop(taken);
+        assert_eq!(count_c.get(), 1);
+    }
+
     #[test]
     #[should_panic(expected = "unexpected union member")]
     fn invalid_accessor_get() {